@@ -1,63 +1,543 @@
-use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSchema, BorshSerialize};
 use near_sdk::{
-    env, near_bindgen, BorshStorageKey, PanicOnDefault, Timestamp,
+    env, ext_contract, near_bindgen, AccountId, BorshStorageKey, Gas, PanicOnDefault, Promise,
+    PromiseError, Timestamp,
 };
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::json_types::U128;
-use near_sdk::collections::UnorderedMap;
+use near_sdk::collections::{UnorderedMap, UnorderedSet};
+
+const SIGN_GAS: Gas = Gas(50_000_000_000_000);
+const CALLBACK_GAS: Gas = Gas(10_000_000_000_000);
 
 #[derive(BorshSerialize, BorshStorageKey)]
 enum StorageKey {
     Bets,
+    Oracles,
+    /// Storage prefix for the `VersionedBet`-typed `bets` map. Deliberately distinct from
+    /// the legacy `Bets` prefix: reusing `Bets` would make a freshly constructed
+    /// `UnorderedMap` collide with the old map's already-persisted length/index bookkeeping
+    /// at that prefix and panic on the first migrated insert.
+    BetsV1,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub enum BetStatus {
     Unfunded,
     Live,
     Resolved,
     Inconclusive,
+    PendingResolution,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct StatusChange {
     pub status: BetStatus,
     pub timestamp: Timestamp,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+/// One side of a pooled bet: a chain-signature deposit path, its stake, and the outcome
+/// it backs.
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Participant {
+    pub deposit_path: String,
+    pub stake: U128,
+    pub outcome: String,
+}
+
+/// An outcome submitted by a registered oracle for a bet under resolution.
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum OracleOutcome {
+    Winner(String),
+    Inconclusive,
+}
+
+/// A single oracle's vote on a bet's outcome.
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OracleSubmission {
+    pub account_id: AccountId,
+    pub outcome: OracleOutcome,
+    pub timestamp: Timestamp,
+}
+
+/// A settlement payout signed via the MPC chain-signature contract.
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Payout {
+    pub deposit_path: String,
+    pub amount: U128,
+    pub tx_hash: String,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct Bet {
     pub id: u64,
-    pub participant1_deposit_path: String,
-    pub participant2_deposit_path: String,
+    pub participants: Vec<Participant>,
     pub amount: U128,
     pub status: BetStatus,
     pub created_at: Timestamp,
     pub last_status_change: Timestamp,
     pub status_history: Vec<StatusChange>,
     pub resolution_criteria: String,
+    pub winning_outcome: Option<String>,
+    pub payouts: Vec<Payout>,
+    pub dispute_window_ns: u64,
+    pub oracle_submissions: Vec<OracleSubmission>,
+    pub pending_outcome: Option<OracleOutcome>,
+    pub threshold_met_at: Option<Timestamp>,
+}
+
+/// Versioned wrapper around [`Bet`] so the contract's storage layout can evolve without
+/// bricking already-deployed state: old records stay readable as their original variant,
+/// are upgraded in memory on read, and are rewritten as the current version by
+/// [`BettingContract::migrate`] or by any call that stores the bet again.
+#[derive(BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum VersionedBet {
+    V1(Bet),
+}
+
+impl From<Bet> for VersionedBet {
+    fn from(bet: Bet) -> Self {
+        VersionedBet::V1(bet)
+    }
+}
+
+impl From<VersionedBet> for Bet {
+    fn from(versioned: VersionedBet) -> Self {
+        match versioned {
+            VersionedBet::V1(bet) => bet,
+        }
+    }
+}
+
+/// Request format expected by the NEAR MPC chain-signature contract's `sign` method.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignRequest {
+    pub payload: [u8; 32],
+    pub path: String,
+    pub key_version: u32,
+}
+
+/// The signature returned by the MPC chain-signature contract.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignResult {
+    pub big_r: String,
+    pub s: String,
+    pub recovery_id: u8,
+}
+
+const EVENT_STANDARD: &str = "betting";
+const EVENT_VERSION: &str = "1.0.0";
+
+/// Typed, NEP-297-compliant events describing a bet's lifecycle: creation, participants
+/// joining, status transitions and each signed payout. Oracle registry changes and
+/// individual oracle submissions are not emitted as events.
+/// Serializes (via [`emit`]) as `{"standard", "version", "event", "data"}` so indexers can
+/// parse state changes without regexing log strings.
+#[derive(Serialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum BetEvent {
+    BetCreated {
+        bet_id: u64,
+        resolution_criteria: String,
+        dispute_window_ns: u64,
+    },
+    ParticipantAdded {
+        bet_id: u64,
+        deposit_path: String,
+        stake: U128,
+        outcome: String,
+    },
+    StatusChanged {
+        bet_id: u64,
+        from: BetStatus,
+        to: BetStatus,
+        timestamp: Timestamp,
+    },
+    Resolved {
+        bet_id: u64,
+        winning_outcome: Option<String>,
+        payout: Payout,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct NepEvent {
+    standard: &'static str,
+    version: &'static str,
+    #[serde(flatten)]
+    event: BetEvent,
+}
+
+/// Formats `event` as a NEP-297 `EVENT_JSON:` log line.
+fn emit(event: BetEvent) {
+    let log = NepEvent {
+        standard: EVENT_STANDARD,
+        version: EVENT_VERSION,
+        event,
+    };
+    env::log_str(&format!(
+        "EVENT_JSON:{}",
+        near_sdk::serde_json::to_string(&log).unwrap()
+    ));
+}
+
+#[ext_contract(ext_mpc)]
+trait MpcContract {
+    fn sign(&self, request: SignRequest) -> SignResult;
+}
+
+#[ext_contract(ext_self)]
+trait SelfCallbacks {
+    fn on_payout_signed(&mut self, bet_id: u64, deposit_path: String, amount: U128) -> Payout;
 }
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct BettingContract {
+    bets: UnorderedMap<u64, VersionedBet>,
+    next_bet_id: u64,
+    mpc_contract_id: AccountId,
+    owner_id: AccountId,
+    oracles: UnorderedSet<AccountId>,
+    oracle_threshold: u32,
+    max_participants: u32,
+}
+
+/// Mirrors [`BettingContract`]'s pre-versioning storage layout, where `bets` held raw
+/// (unwrapped) `Bet` values. `migrate()` reads already-deployed state through this type
+/// instead of through `BettingContract` itself, since the latter's `bets` field is
+/// `VersionedBet`-typed and would misinterpret legacy `Bet` bytes as an enum discriminant.
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BettingContractV0 {
     bets: UnorderedMap<u64, Bet>,
     next_bet_id: u64,
+    mpc_contract_id: AccountId,
+    owner_id: AccountId,
+    oracles: UnorderedSet<AccountId>,
+    oracle_threshold: u32,
+    max_participants: u32,
+}
+
+/// Mirrors the `BetStatus` ordering as chunk0-2 actually serialized it, before d69ef21
+/// moved `PendingResolution` to the end: `PendingResolution` sat at discriminant 2, with
+/// `Resolved`/`Inconclusive` at 3/4. A chunk0-2-era bet can be sitting in
+/// `PendingResolution` (mid dispute-window) at upgrade time same as any other status, so
+/// this must cover every variant `BetStatus` had then. Kept distinct from [`BetStatus`] so
+/// legacy bytes are read at the discriminants they were really written with, then mapped
+/// across by variant name rather than by numeric value.
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Debug, Clone)]
+enum BetStatusLegacy {
+    Unfunded,
+    Live,
+    PendingResolution,
+    Resolved,
+    Inconclusive,
+}
+
+impl From<BetStatusLegacy> for BetStatus {
+    fn from(status: BetStatusLegacy) -> Self {
+        match status {
+            BetStatusLegacy::Unfunded => BetStatus::Unfunded,
+            BetStatusLegacy::Live => BetStatus::Live,
+            BetStatusLegacy::PendingResolution => BetStatus::PendingResolution,
+            BetStatusLegacy::Resolved => BetStatus::Resolved,
+            BetStatusLegacy::Inconclusive => BetStatus::Inconclusive,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct StatusChangeLegacy {
+    status: BetStatusLegacy,
+    timestamp: Timestamp,
+}
+
+/// Which side of a two-party bet a deposit path belonged to, before pooled
+/// `Participant`s replaced fixed sides.
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone)]
+enum ParticipantSideLegacy {
+    Participant1,
+    Participant2,
+}
+
+impl ParticipantSideLegacy {
+    /// The `Participant::outcome` a side maps to, matching the label
+    /// [`BettingContract::participants_from_legacy_pair`] already gives each side.
+    fn outcome(&self) -> String {
+        match self {
+            ParticipantSideLegacy::Participant1 => "participant_1".to_string(),
+            ParticipantSideLegacy::Participant2 => "participant_2".to_string(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, PartialEq, Clone)]
+enum OracleOutcomeLegacy {
+    Winner(ParticipantSideLegacy),
+    Inconclusive,
+}
+
+impl From<OracleOutcomeLegacy> for OracleOutcome {
+    fn from(outcome: OracleOutcomeLegacy) -> Self {
+        match outcome {
+            OracleOutcomeLegacy::Winner(side) => OracleOutcome::Winner(side.outcome()),
+            OracleOutcomeLegacy::Inconclusive => OracleOutcome::Inconclusive,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+struct OracleSubmissionLegacy {
+    account_id: AccountId,
+    outcome: OracleOutcomeLegacy,
+    timestamp: Timestamp,
+}
+
+impl From<OracleSubmissionLegacy> for OracleSubmission {
+    fn from(submission: OracleSubmissionLegacy) -> Self {
+        OracleSubmission {
+            account_id: submission.account_id,
+            outcome: submission.outcome.into(),
+            timestamp: submission.timestamp,
+        }
+    }
+}
+
+/// Mirrors the fixed two-party `Bet` layout from before chunk0-3 generalized it to pooled
+/// `Participant`s: a single `amount` covering both `participant1_deposit_path` and
+/// `participant2_deposit_path`, rather than each side's own stake. Read through by
+/// [`BettingContract::migrate_legacy_two_party`], the migration step that chunk0-3's
+/// reshape should have shipped with.
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BetLegacy {
+    id: u64,
+    participant1_deposit_path: String,
+    participant2_deposit_path: String,
+    amount: U128,
+    status: BetStatusLegacy,
+    created_at: Timestamp,
+    last_status_change: Timestamp,
+    status_history: Vec<StatusChangeLegacy>,
+    resolution_criteria: String,
+    winner: Option<ParticipantSideLegacy>,
+    payouts: Vec<Payout>,
+    dispute_window_ns: u64,
+    oracle_submissions: Vec<OracleSubmissionLegacy>,
+    pending_outcome: Option<OracleOutcomeLegacy>,
+    threshold_met_at: Option<Timestamp>,
+}
+
+impl From<BetLegacy> for Bet {
+    fn from(bet: BetLegacy) -> Self {
+        Bet {
+            id: bet.id,
+            participants: BettingContract::participants_from_legacy_pair(
+                bet.participant1_deposit_path,
+                bet.participant2_deposit_path,
+                bet.amount,
+            ),
+            amount: bet.amount,
+            status: bet.status.into(),
+            created_at: bet.created_at,
+            last_status_change: bet.last_status_change,
+            status_history: bet
+                .status_history
+                .into_iter()
+                .map(|change| StatusChange {
+                    status: change.status.into(),
+                    timestamp: change.timestamp,
+                })
+                .collect(),
+            resolution_criteria: bet.resolution_criteria,
+            winning_outcome: bet.winner.map(|side| side.outcome()),
+            payouts: bet.payouts,
+            dispute_window_ns: bet.dispute_window_ns,
+            oracle_submissions: bet
+                .oracle_submissions
+                .into_iter()
+                .map(OracleSubmission::from)
+                .collect(),
+            pending_outcome: bet.pending_outcome.map(OracleOutcome::from),
+            threshold_met_at: bet.threshold_met_at,
+        }
+    }
+}
+
+/// Mirrors [`BettingContract`]'s fixed two-party, pre-oracle-reorder storage layout (the
+/// shape deployed by chunk0-2), for contracts upgrading straight to the current code
+/// without having passed through an intermediate deploy of chunk0-3/chunk0-4. Has no
+/// `max_participants`, since that field didn't exist until chunk0-3.
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BettingContractLegacyTwoParty {
+    bets: UnorderedMap<u64, BetLegacy>,
+    next_bet_id: u64,
+    mpc_contract_id: AccountId,
+    owner_id: AccountId,
+    oracles: UnorderedSet<AccountId>,
+    oracle_threshold: u32,
 }
 
 #[near_bindgen]
 impl BettingContract {
     #[init]
-    pub fn new() -> Self {
+    pub fn new(
+        mpc_contract_id: AccountId,
+        owner_id: AccountId,
+        oracle_threshold: u32,
+        max_participants: u32,
+    ) -> Self {
         Self {
-            bets: UnorderedMap::new(StorageKey::Bets),
+            bets: UnorderedMap::new(StorageKey::BetsV1),
             next_bet_id: 0,
+            mpc_contract_id,
+            owner_id,
+            oracles: UnorderedSet::new(StorageKey::Oracles),
+            oracle_threshold,
+            max_participants,
+        }
+    }
+
+    fn assert_owner(&self) {
+        assert_eq!(
+            env::predecessor_account_id(),
+            self.owner_id,
+            "Only the contract owner may call this method"
+        );
+    }
+
+    /// Reads `bet_id`, transparently upgrading a legacy-version record to the current
+    /// [`Bet`] layout. The upgrade is not persisted here; callers that mutate the bet
+    /// persist the current version through [`Self::insert_bet`] as usual.
+    fn get_bet_internal(&self, bet_id: u64) -> Option<Bet> {
+        self.bets.get(&bet_id).map(Bet::from)
+    }
+
+    fn insert_bet(&mut self, bet_id: u64, bet: Bet) {
+        self.bets.insert(&bet_id, &VersionedBet::from(bet));
+    }
+
+    /// Upgrades already-deployed state from the pre-versioning layout (raw `Bet` values)
+    /// to the current `VersionedBet`-wrapped one. Reads through [`BettingContractV0`] so
+    /// legacy bytes are deserialized as the `Bet` they actually are, then re-wraps and
+    /// re-inserts every entry under the fresh `StorageKey::BetsV1` prefix — a newly
+    /// constructed `UnorderedMap` has no notion of the old map's persisted length/index
+    /// bookkeeping, so writing into the old `Bets` prefix would collide with it and panic
+    /// on the first insert. The old `Bets`-prefixed entries are cleared once copied, so
+    /// deployed state doesn't keep paying storage staking for both copies.
+    ///
+    /// Must be called exactly once, as the single migration step of a code upgrade. A
+    /// second call would read the just-migrated `VersionedBet` bytes back in through
+    /// [`BettingContractV0`]'s raw-`Bet` shape and corrupt them; there is no marker that
+    /// distinguishes a legacy deployment from an already-migrated one at this struct shape,
+    /// so this is an operational invariant (upgrade + migrate as a single deploy step), not
+    /// one this function can check for itself.
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let mut old: BettingContractV0 = env::state_read().expect("Failed to read old state");
+        let mut bets: UnorderedMap<u64, VersionedBet> = UnorderedMap::new(StorageKey::BetsV1);
+        for (bet_id, bet) in old.bets.iter() {
+            bets.insert(&bet_id, &VersionedBet::from(bet));
+        }
+        old.bets.clear();
+        Self {
+            bets,
+            next_bet_id: old.next_bet_id,
+            mpc_contract_id: old.mpc_contract_id,
+            owner_id: old.owner_id,
+            oracles: old.oracles,
+            oracle_threshold: old.oracle_threshold,
+            max_participants: old.max_participants,
+        }
+    }
+
+    /// Upgrades already-deployed state from the fixed two-party, pre-oracle-reorder
+    /// layout (the shape chunk0-2 shipped) directly to the current `VersionedBet`-wrapped
+    /// one — the migration step chunk0-3's generalization to pooled `Participant`s should
+    /// have shipped with, for any deployment upgrading straight from that layout rather
+    /// than through an intermediate chunk0-3/chunk0-4 deploy. `max_participants` isn't part
+    /// of the legacy state, so it's supplied here same as [`Self::new`].
+    ///
+    /// Same one-shot caveat as [`Self::migrate`]: use this instead of `migrate()` only when
+    /// the deployed state is actually in this older shape, and only once.
+    #[init(ignore_state)]
+    pub fn migrate_legacy_two_party(max_participants: u32) -> Self {
+        let mut old: BettingContractLegacyTwoParty =
+            env::state_read().expect("Failed to read old state");
+        let mut bets: UnorderedMap<u64, VersionedBet> = UnorderedMap::new(StorageKey::BetsV1);
+        for (bet_id, bet) in old.bets.iter() {
+            bets.insert(&bet_id, &VersionedBet::from(Bet::from(bet)));
+        }
+        old.bets.clear();
+        Self {
+            bets,
+            next_bet_id: old.next_bet_id,
+            mpc_contract_id: old.mpc_contract_id,
+            owner_id: old.owner_id,
+            oracles: old.oracles,
+            oracle_threshold: old.oracle_threshold,
+            max_participants,
         }
     }
 
+    /// Registers `account_id` as an oracle eligible to submit outcomes.
+    pub fn add_oracle(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.oracles.insert(&account_id);
+    }
+
+    /// Deregisters `account_id` as an oracle.
+    pub fn remove_oracle(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.oracles.remove(&account_id);
+    }
+
+    /// Sets the number of matching oracle submissions required to reach a pending resolution.
+    pub fn set_oracle_threshold(&mut self, oracle_threshold: u32) {
+        self.assert_owner();
+        self.oracle_threshold = oracle_threshold;
+    }
+
+    /// Maps a legacy two-party bet onto the pooled `Participant` representation: each side
+    /// stakes half of `amount` and backs an outcome named after its own position, so
+    /// `resolve_bet`/oracle submissions can keep referring to `"participant_1"` /
+    /// `"participant_2"` exactly as the old `ParticipantSide` enum did.
+    fn participants_from_legacy_pair(
+        participant1_deposit_path: String,
+        participant2_deposit_path: String,
+        amount: U128,
+    ) -> Vec<Participant> {
+        assert_ne!(
+            participant1_deposit_path, participant2_deposit_path,
+            "A single deposit path may not occupy two sides of a bet"
+        );
+        let stake2 = U128(amount.0 / 2);
+        let stake1 = U128(amount.0 - stake2.0);
+        vec![
+            Participant {
+                deposit_path: participant1_deposit_path,
+                stake: stake1,
+                outcome: "participant_1".to_string(),
+            },
+            Participant {
+                deposit_path: participant2_deposit_path,
+                stake: stake2,
+                outcome: "participant_2".to_string(),
+            },
+        ]
+    }
+
     #[payable]
     pub fn new_bet(
         &mut self,
@@ -65,10 +545,11 @@ impl BettingContract {
         participant2_deposit_path: String,
         amount: U128,
         resolution_criteria: String,
+        dispute_window_ns: u64,
     ) -> u64 {
         let bet_id = self.next_bet_id;
         self.next_bet_id += 1;
-        
+
         let current_time = env::block_timestamp();
         let initial_status = StatusChange {
             status: BetStatus::Unfunded,
@@ -77,49 +558,376 @@ impl BettingContract {
 
         let bet = Bet {
             id: bet_id,
-            participant1_deposit_path,
-            participant2_deposit_path,
+            participants: Self::participants_from_legacy_pair(
+                participant1_deposit_path,
+                participant2_deposit_path,
+                amount,
+            ),
             amount,
             status: BetStatus::Unfunded,
             created_at: current_time,
             last_status_change: current_time,
             status_history: vec![initial_status],
             resolution_criteria,
+            winning_outcome: None,
+            payouts: Vec::new(),
+            dispute_window_ns,
+            oracle_submissions: Vec::new(),
+            pending_outcome: None,
+            threshold_met_at: None,
         };
-        self.bets.insert(&bet_id, &bet);
-        env::log_str(&format!("New bet created with id {}", bet_id));
+        let resolution_criteria = bet.resolution_criteria.clone();
+        let dispute_window_ns = bet.dispute_window_ns;
+        self.insert_bet(bet_id, bet);
+        emit(BetEvent::BetCreated {
+            bet_id,
+            resolution_criteria,
+            dispute_window_ns,
+        });
         bet_id
     }
 
-    pub fn update_bet_state(&mut self, bet_id: u64, new_status: BetStatus) {
-        let mut bet = self.bets.get(&bet_id).expect("Bet not found");
+    /// Adds a participant staking `stake` on `outcome`, as long as the bet is `Unfunded` or
+    /// `Live` and hasn't reached `max_participants`. Rejects a `deposit_path` that already backs
+    /// another position in the same bet.
+    #[payable]
+    pub fn add_participant(&mut self, bet_id: u64, deposit_path: String, stake: U128, outcome: String) {
+        let mut bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert!(
+            matches!(bet.status, BetStatus::Unfunded | BetStatus::Live),
+            "Bet is not accepting participants"
+        );
+        assert!(
+            (bet.participants.len() as u32) < self.max_participants,
+            "Bet has reached its maximum participant count"
+        );
+        assert!(
+            !bet.participants.iter().any(|p| p.deposit_path == deposit_path),
+            "Deposit path already occupies a position in this bet"
+        );
+
+        bet.amount = U128(bet.amount.0 + stake.0);
+        bet.participants.push(Participant {
+            deposit_path: deposit_path.clone(),
+            stake,
+            outcome: outcome.clone(),
+        });
+        self.insert_bet(bet_id, bet);
+        emit(BetEvent::ParticipantAdded {
+            bet_id,
+            deposit_path,
+            stake,
+            outcome,
+        });
+    }
+
+    /// Returns the participants staked into a bet.
+    pub fn get_participants(&self, bet_id: u64) -> Vec<Participant> {
+        self.get_bet_internal(bet_id)
+            .map(|bet| bet.participants)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether `to` is a legal next status for a bet currently in `from`.
+    ///
+    /// `Live -> PendingResolution` happens once M-of-N oracles agree (see
+    /// [`Self::submit_outcome`]); `PendingResolution` only settles through
+    /// [`Self::finalize_resolution`] once the dispute window elapses, or can be overturned
+    /// back to `Inconclusive` by a supermajority of oracles. `Live -> Resolved` /
+    /// `Live -> Inconclusive` remain for the owner's manual [`Self::resolve_bet`] /
+    /// [`Self::refund_bet`] override; `update_bet_state` is restricted to the funding
+    /// transition.
+    fn allowed(from: &BetStatus, to: &BetStatus) -> bool {
+        matches!(
+            (from, to),
+            (BetStatus::Unfunded, BetStatus::Live)
+                | (BetStatus::Live, BetStatus::Resolved)
+                | (BetStatus::Live, BetStatus::Inconclusive)
+                | (BetStatus::Live, BetStatus::PendingResolution)
+                | (BetStatus::PendingResolution, BetStatus::Resolved)
+                | (BetStatus::PendingResolution, BetStatus::Inconclusive)
+        )
+    }
+
+    fn transition(&self, bet: &mut Bet, new_status: BetStatus) {
+        assert!(
+            Self::allowed(&bet.status, &new_status),
+            "Illegal transition from {:?} to {:?}",
+            bet.status,
+            new_status
+        );
         let current_time = env::block_timestamp();
-        
+        let from = bet.status.clone();
+        bet.status = new_status.clone();
+        bet.last_status_change = current_time;
+        bet.status_history.push(StatusChange {
+            status: new_status.clone(),
+            timestamp: current_time,
+        });
+        emit(BetEvent::StatusChanged {
+            bet_id: bet.id,
+            from,
+            to: new_status,
+            timestamp: current_time,
+        });
+    }
+
+    pub fn update_bet_state(&mut self, bet_id: u64, new_status: BetStatus) {
+        let mut bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert!(
+            !matches!(
+                new_status,
+                BetStatus::Resolved | BetStatus::Inconclusive | BetStatus::PendingResolution
+            ),
+            "Use submit_outcome/finalize_resolution or resolve_bet/refund_bet to settle a Live bet"
+        );
+
         // Only update if status is actually changing
         if bet.status != new_status {
-            bet.status = new_status.clone();
-            bet.last_status_change = current_time;
-            bet.status_history.push(StatusChange {
-                status: new_status,
-                timestamp: current_time,
-            });
-            self.bets.insert(&bet_id, &bet);
-            env::log_str(&format!("Bet {} updated to status {:?}", bet_id, bet.status));
+            self.transition(&mut bet, new_status);
+            self.insert_bet(bet_id, bet);
+        }
+    }
+
+    /// Records `outcome` from a registered oracle. Once `oracle_threshold` oracles agree on
+    /// the same outcome, the bet moves to `PendingResolution` and starts its dispute window.
+    /// While pending, a supermajority of oracles voting a different outcome overturns it
+    /// straight to `Inconclusive`.
+    pub fn submit_outcome(&mut self, bet_id: u64, outcome: OracleOutcome) {
+        let account_id = env::predecessor_account_id();
+        assert!(self.oracles.contains(&account_id), "Not a registered oracle");
+        let mut bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert!(
+            matches!(bet.status, BetStatus::Live | BetStatus::PendingResolution),
+            "Bet is not open for oracle submissions"
+        );
+
+        bet.oracle_submissions.retain(|s| s.account_id != account_id);
+        bet.oracle_submissions.push(OracleSubmission {
+            account_id,
+            outcome: outcome.clone(),
+            timestamp: env::block_timestamp(),
+        });
+
+        match bet.status {
+            BetStatus::Live => {
+                let matching = bet
+                    .oracle_submissions
+                    .iter()
+                    .filter(|s| self.oracles.contains(&s.account_id) && s.outcome == outcome)
+                    .count() as u32;
+                if matching >= self.oracle_threshold {
+                    bet.pending_outcome = Some(outcome);
+                    bet.threshold_met_at = Some(env::block_timestamp());
+                    self.transition(&mut bet, BetStatus::PendingResolution);
+                }
+            }
+            BetStatus::PendingResolution => {
+                let pending = bet
+                    .pending_outcome
+                    .clone()
+                    .expect("Pending bet missing pending_outcome");
+                if outcome != pending {
+                    let n = self.oracles.len() as u32;
+                    let supermajority = n - n / 3;
+                    let dissent = bet
+                        .oracle_submissions
+                        .iter()
+                        .filter(|s| self.oracles.contains(&s.account_id) && s.outcome != pending)
+                        .count() as u32;
+                    if dissent >= supermajority {
+                        self.transition(&mut bet, BetStatus::Inconclusive);
+                    }
+                }
+            }
+            _ => unreachable!("checked above"),
         }
+
+        self.insert_bet(bet_id, bet);
+    }
+
+    /// Finalizes a `PendingResolution` bet once its dispute window has elapsed, settling it
+    /// per the agreed oracle outcome and constructing the MPC-signed payout.
+    pub fn finalize_resolution(&mut self, bet_id: u64) -> Promise {
+        let bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert_eq!(
+            bet.status,
+            BetStatus::PendingResolution,
+            "Bet is not pending resolution"
+        );
+        let threshold_met_at = bet
+            .threshold_met_at
+            .expect("Pending bet missing threshold_met_at");
+        assert!(
+            env::block_timestamp().saturating_sub(threshold_met_at) >= bet.dispute_window_ns,
+            "Dispute window has not elapsed"
+        );
+
+        let outcome = bet.pending_outcome.clone().expect("Missing pending_outcome");
+        self.settle(bet_id, bet, outcome)
+    }
+
+    /// Returns all bets currently awaiting dispute-window expiry.
+    pub fn get_pending_resolutions(&self) -> Vec<Bet> {
+        self.bets
+            .values()
+            .map(Bet::from)
+            .filter(|bet| bet.status == BetStatus::PendingResolution)
+            .collect()
+    }
+
+    /// Returns the oracle submissions recorded so far for a bet.
+    pub fn get_oracle_submissions(&self, bet_id: u64) -> Vec<OracleSubmission> {
+        self.get_bet_internal(bet_id)
+            .map(|bet| bet.oracle_submissions)
+            .unwrap_or_default()
+    }
+
+    /// Owner-only override: resolves a `Live` bet in favor of `winning_outcome`, splitting
+    /// the pool pro-rata by stake among participants who backed it, via MPC chain-signature
+    /// requests.
+    pub fn resolve_bet(&mut self, bet_id: u64, winning_outcome: String) -> Promise {
+        self.assert_owner();
+        let bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert_eq!(bet.status, BetStatus::Live, "Bet must be Live to resolve");
+        self.settle(bet_id, bet, OracleOutcome::Winner(winning_outcome))
+    }
+
+    /// Owner-only override: marks a `Live` bet `Inconclusive` and refunds each participant
+    /// their own stake via MPC chain-signature requests.
+    pub fn refund_bet(&mut self, bet_id: u64) -> Promise {
+        self.assert_owner();
+        let bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        assert_eq!(bet.status, BetStatus::Live, "Bet must be Live to refund");
+        self.settle(bet_id, bet, OracleOutcome::Inconclusive)
+    }
+
+    /// Settles `bet` per `outcome`: `Winner(outcome)` splits the pool pro-rata by stake
+    /// among the participants who backed that outcome (parimutuel); `Inconclusive` refunds
+    /// every participant their own stake. Either way, each payout is requested as a
+    /// separate MPC chain-signature request to that participant's deposit path.
+    fn settle(&mut self, bet_id: u64, mut bet: Bet, outcome: OracleOutcome) -> Promise {
+        match outcome {
+            OracleOutcome::Winner(winning_outcome) => {
+                let winners: Vec<(String, u128)> = bet
+                    .participants
+                    .iter()
+                    .filter(|p| p.outcome == winning_outcome)
+                    .map(|p| (p.deposit_path.clone(), p.stake.0))
+                    .collect();
+                assert!(!winners.is_empty(), "No participant picked the winning outcome");
+                let pool = bet.amount.0;
+                let payouts = Self::split_pool_pro_rata(&winners, pool);
+
+                bet.winning_outcome = Some(winning_outcome);
+                self.transition(&mut bet, BetStatus::Resolved);
+                self.insert_bet(bet_id, bet);
+
+                payouts
+                    .into_iter()
+                    .map(|(deposit_path, amount)| {
+                        self.request_payout(bet_id, deposit_path, U128(amount))
+                    })
+                    .reduce(|acc, promise| acc.and(promise))
+                    .expect("No participant picked the winning outcome")
+            }
+            OracleOutcome::Inconclusive => {
+                self.transition(&mut bet, BetStatus::Inconclusive);
+                let participants = bet.participants.clone();
+                self.insert_bet(bet_id, bet);
+
+                participants
+                    .into_iter()
+                    .map(|p| self.request_payout(bet_id, p.deposit_path, p.stake))
+                    .reduce(|acc, promise| acc.and(promise))
+                    .expect("Bet has no participants to refund")
+            }
+        }
+    }
+
+    /// Splits `pool` pro-rata by stake among `winners`. Every winner but the last is
+    /// rounded down to the nearest yoctoNEAR; the last winner receives `pool` minus
+    /// everything already paid out, so the truncated remainder lands with a real
+    /// participant instead of being silently left unpaid.
+    fn split_pool_pro_rata(winners: &[(String, u128)], pool: u128) -> Vec<(String, u128)> {
+        let total_winning_stake: u128 = winners.iter().map(|(_, stake)| stake).sum();
+        let last_index = winners.len() - 1;
+        let mut paid_so_far: u128 = 0;
+        winners
+            .iter()
+            .enumerate()
+            .map(|(i, (deposit_path, stake))| {
+                let amount = if i == last_index {
+                    pool - paid_so_far
+                } else {
+                    pool.checked_mul(*stake)
+                        .expect("Payout calculation overflowed u128")
+                        / total_winning_stake
+                };
+                paid_so_far += amount;
+                (deposit_path.clone(), amount)
+            })
+            .collect()
+    }
+
+    fn request_payout(&self, bet_id: u64, deposit_path: String, amount: U128) -> Promise {
+        let mut payload = [0u8; 32];
+        payload[..16].copy_from_slice(&amount.0.to_le_bytes());
+
+        ext_mpc::ext(self.mpc_contract_id.clone())
+            .with_static_gas(SIGN_GAS)
+            .sign(SignRequest {
+                payload,
+                path: deposit_path.clone(),
+                key_version: 0,
+            })
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_static_gas(CALLBACK_GAS)
+                    .on_payout_signed(bet_id, deposit_path, amount),
+            )
+    }
+
+    #[private]
+    pub fn on_payout_signed(
+        &mut self,
+        bet_id: u64,
+        deposit_path: String,
+        amount: U128,
+        #[callback_result] result: Result<SignResult, PromiseError>,
+    ) -> Payout {
+        let sig = result.expect("MPC signature request failed");
+        let payout = Payout {
+            deposit_path,
+            amount,
+            tx_hash: format!("{}:{}", sig.big_r, sig.s),
+        };
+
+        let mut bet = self.get_bet_internal(bet_id).expect("Bet not found");
+        bet.payouts.push(payout.clone());
+        let winning_outcome = bet.winning_outcome.clone();
+        self.insert_bet(bet_id, bet);
+        emit(BetEvent::Resolved {
+            bet_id,
+            winning_outcome,
+            payout: payout.clone(),
+        });
+        payout
     }
 
     pub fn get_bet(&self, bet_id: u64) -> Option<Bet> {
-        self.bets.get(&bet_id)
+        self.get_bet_internal(bet_id)
     }
 
     pub fn get_all_bets(&self) -> Vec<Bet> {
-        self.bets.values().collect()
+        self.bets.values().map(Bet::from).collect()
     }
 
     /// Returns all bets with the specified status
     pub fn get_bets_by_status(&self, status: BetStatus) -> Vec<Bet> {
         self.bets
             .values()
+            .map(Bet::from)
             .filter(|bet| bet.status == status)
             .collect()
     }
@@ -130,8 +938,9 @@ impl BettingContract {
         let current_time = env::block_timestamp();
         self.bets
             .values()
+            .map(Bet::from)
             .filter(|bet| {
-                bet.status == status && 
+                bet.status == status &&
                 current_time.saturating_sub(bet.last_status_change) >= min_age_ns
             })
             .collect()
@@ -139,6 +948,334 @@ impl BettingContract {
 
     /// Returns the complete status change history for a bet
     pub fn get_bet_status_history(&self, bet_id: u64) -> Option<Vec<StatusChange>> {
-        self.bets.get(&bet_id).map(|bet| bet.status_history)
+        self.get_bet_internal(bet_id).map(|bet| bet.status_history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::testing_env;
+
+    fn test_contract() -> BettingContract {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id("owner.testnet".parse().unwrap())
+            .build());
+        BettingContract::new(
+            "mpc.testnet".parse().unwrap(),
+            "owner.testnet".parse().unwrap(),
+            1,
+            8,
+        )
+    }
+
+    /// Switches the predecessor for the next call(s). The rest of the context, block
+    /// timestamp included, is reset to `VMContextBuilder` defaults; storage is kept.
+    fn set_predecessor(account_id: &str) {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(account_id.parse().unwrap())
+            .build());
+    }
+
+    #[test]
+    fn settle_splits_pool_without_losing_dust() {
+        let winners = vec![
+            ("alice.deposit".to_string(), 1u128),
+            ("bob.deposit".to_string(), 1u128),
+            ("carol.deposit".to_string(), 1u128),
+        ];
+        let payouts = BettingContract::split_pool_pro_rata(&winners, 100);
+
+        let total: u128 = payouts.iter().map(|(_, amount)| amount).sum();
+        assert_eq!(total, 100, "the full pool must be paid out, with no dust left behind");
+        assert_eq!(payouts[0].1, 33);
+        assert_eq!(payouts[1].1, 33);
+        assert_eq!(payouts[2].1, 34, "the last winner absorbs the truncated remainder");
+    }
+
+    #[test]
+    #[should_panic(expected = "Deposit path already occupies a position in this bet")]
+    fn add_participant_rejects_duplicate_deposit_path() {
+        let mut contract = test_contract();
+        let bet_id = contract.new_bet(
+            "alice.deposit".to_string(),
+            "bob.deposit".to_string(),
+            U128(100),
+            "Who wins?".to_string(),
+            0,
+        );
+        contract.add_participant(
+            bet_id,
+            "alice.deposit".to_string(),
+            U128(10),
+            "participant_1".to_string(),
+        );
+    }
+
+    #[test]
+    fn migrate_upgrades_legacy_bets_to_versioned() {
+        testing_env!(VMContextBuilder::new().build());
+
+        let legacy_bet = Bet {
+            id: 0,
+            participants: vec![],
+            amount: U128(100),
+            status: BetStatus::Unfunded,
+            created_at: 0,
+            last_status_change: 0,
+            status_history: vec![],
+            resolution_criteria: "Legacy bet".to_string(),
+            winning_outcome: None,
+            payouts: vec![],
+            dispute_window_ns: 0,
+            oracle_submissions: vec![],
+            pending_outcome: None,
+            threshold_met_at: None,
+        };
+        let mut legacy_bets: UnorderedMap<u64, Bet> = UnorderedMap::new(StorageKey::Bets);
+        legacy_bets.insert(&0, &legacy_bet);
+        let legacy_state = BettingContractV0 {
+            bets: legacy_bets,
+            next_bet_id: 1,
+            mpc_contract_id: "mpc.testnet".parse().unwrap(),
+            owner_id: "owner.testnet".parse().unwrap(),
+            oracles: UnorderedSet::new(StorageKey::Oracles),
+            oracle_threshold: 1,
+            max_participants: 8,
+        };
+        env::state_write(&legacy_state);
+
+        let migrated = BettingContract::migrate();
+
+        assert_eq!(migrated.next_bet_id, 1);
+        let upgraded = migrated.get_bet(0).expect("migrated bet should still be present");
+        assert_eq!(upgraded.resolution_criteria, "Legacy bet");
+        assert_eq!(upgraded.amount, U128(100));
+    }
+
+    #[test]
+    fn migrate_legacy_two_party_upgrades_pending_resolution_bet() {
+        testing_env!(VMContextBuilder::new().build());
+
+        let legacy_bet = BetLegacy {
+            id: 0,
+            participant1_deposit_path: "alice.deposit".to_string(),
+            participant2_deposit_path: "bob.deposit".to_string(),
+            amount: U128(101),
+            status: BetStatusLegacy::PendingResolution,
+            created_at: 0,
+            last_status_change: 0,
+            status_history: vec![
+                StatusChangeLegacy {
+                    status: BetStatusLegacy::Unfunded,
+                    timestamp: 0,
+                },
+                StatusChangeLegacy {
+                    status: BetStatusLegacy::Live,
+                    timestamp: 0,
+                },
+                StatusChangeLegacy {
+                    status: BetStatusLegacy::PendingResolution,
+                    timestamp: 0,
+                },
+            ],
+            resolution_criteria: "Legacy two-party bet".to_string(),
+            winner: None,
+            payouts: vec![],
+            dispute_window_ns: 1_000_000,
+            oracle_submissions: vec![OracleSubmissionLegacy {
+                account_id: "oracle1.testnet".parse().unwrap(),
+                outcome: OracleOutcomeLegacy::Winner(ParticipantSideLegacy::Participant1),
+                timestamp: 0,
+            }],
+            pending_outcome: Some(OracleOutcomeLegacy::Winner(ParticipantSideLegacy::Participant1)),
+            threshold_met_at: Some(0),
+        };
+        let mut legacy_bets: UnorderedMap<u64, BetLegacy> = UnorderedMap::new(StorageKey::Bets);
+        legacy_bets.insert(&0, &legacy_bet);
+        let legacy_state = BettingContractLegacyTwoParty {
+            bets: legacy_bets,
+            next_bet_id: 1,
+            mpc_contract_id: "mpc.testnet".parse().unwrap(),
+            owner_id: "owner.testnet".parse().unwrap(),
+            oracles: UnorderedSet::new(StorageKey::Oracles),
+            oracle_threshold: 1,
+        };
+        env::state_write(&legacy_state);
+
+        let migrated = BettingContract::migrate_legacy_two_party(8);
+
+        assert_eq!(migrated.next_bet_id, 1);
+        let upgraded = migrated.get_bet(0).expect("migrated bet should still be present");
+        assert_eq!(upgraded.status, BetStatus::PendingResolution);
+        assert_eq!(
+            upgraded.participants.iter().map(|p| p.deposit_path.clone()).collect::<Vec<_>>(),
+            vec!["alice.deposit".to_string(), "bob.deposit".to_string()]
+        );
+        assert_eq!(
+            upgraded.participants.iter().map(|p| p.outcome.clone()).collect::<Vec<_>>(),
+            vec!["participant_1".to_string(), "participant_2".to_string()]
+        );
+        assert_eq!(
+            upgraded.pending_outcome,
+            Some(OracleOutcome::Winner("participant_1".to_string()))
+        );
+        assert_eq!(upgraded.oracle_submissions.len(), 1);
+        assert_eq!(
+            upgraded.oracle_submissions[0].outcome,
+            OracleOutcome::Winner("participant_1".to_string())
+        );
+        assert_eq!(upgraded.threshold_met_at, Some(0));
+        assert_eq!(
+            upgraded.status_history.iter().map(|c| c.status.clone()).collect::<Vec<_>>(),
+            vec![BetStatus::Unfunded, BetStatus::Live, BetStatus::PendingResolution]
+        );
+    }
+
+    #[test]
+    fn transition_graph_is_exhaustive() {
+        use BetStatus::*;
+        let all = [Unfunded, Live, PendingResolution, Resolved, Inconclusive];
+        for from in &all {
+            for to in &all {
+                let expected = matches!(
+                    (from, to),
+                    (Unfunded, Live)
+                        | (Live, Resolved)
+                        | (Live, Inconclusive)
+                        | (Live, PendingResolution)
+                        | (PendingResolution, Resolved)
+                        | (PendingResolution, Inconclusive)
+                );
+                assert_eq!(
+                    BettingContract::allowed(from, to),
+                    expected,
+                    "allowed({:?}, {:?})",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_states_reject_all_transitions() {
+        use BetStatus::*;
+        for terminal in [Resolved, Inconclusive] {
+            for to in [Unfunded, Live, PendingResolution, Resolved, Inconclusive] {
+                assert!(!BettingContract::allowed(&terminal, &to));
+            }
+        }
+    }
+
+    /// Registers three oracles and a two-participant bet already moved to `Live`, leaving
+    /// the oracle-threshold-and-voting mechanics to each test.
+    fn oracle_test_setup(oracle_threshold: u32) -> (BettingContract, u64) {
+        let mut contract = test_contract();
+        set_predecessor("owner.testnet");
+        contract.set_oracle_threshold(oracle_threshold);
+        contract.add_oracle("oracle1.testnet".parse().unwrap());
+        contract.add_oracle("oracle2.testnet".parse().unwrap());
+        contract.add_oracle("oracle3.testnet".parse().unwrap());
+
+        let bet_id = contract.new_bet(
+            "alice.deposit".to_string(),
+            "bob.deposit".to_string(),
+            U128(100),
+            "Who wins?".to_string(),
+            1_000_000,
+        );
+        set_predecessor("owner.testnet");
+        contract.update_bet_state(bet_id, BetStatus::Live);
+        (contract, bet_id)
+    }
+
+    #[test]
+    fn submit_outcome_reaches_pending_resolution_once_threshold_met() {
+        let (mut contract, bet_id) = oracle_test_setup(2);
+
+        set_predecessor("oracle1.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+        assert_eq!(contract.get_bet(bet_id).unwrap().status, BetStatus::Live);
+
+        set_predecessor("oracle2.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+        let bet = contract.get_bet(bet_id).unwrap();
+        assert_eq!(bet.status, BetStatus::PendingResolution);
+        assert_eq!(
+            bet.pending_outcome,
+            Some(OracleOutcome::Winner("participant_1".to_string()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a registered oracle")]
+    fn submit_outcome_rejects_removed_oracle() {
+        let (mut contract, bet_id) = oracle_test_setup(1);
+        set_predecessor("owner.testnet");
+        contract.remove_oracle("oracle1.testnet".parse().unwrap());
+
+        set_predecessor("oracle1.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+    }
+
+    #[test]
+    fn submit_outcome_excludes_removed_oracles_stale_vote_from_threshold() {
+        let (mut contract, bet_id) = oracle_test_setup(2);
+
+        set_predecessor("oracle1.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+
+        set_predecessor("owner.testnet");
+        contract.remove_oracle("oracle1.testnet".parse().unwrap());
+
+        // oracle2's own vote is the only one still cast by a registered oracle, so the
+        // 2-of-N threshold must not count oracle1's now-stale submission toward it.
+        set_predecessor("oracle2.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+        assert_eq!(contract.get_bet(bet_id).unwrap().status, BetStatus::Live);
+    }
+
+    #[test]
+    fn submit_outcome_overturns_to_inconclusive_on_supermajority_dissent() {
+        let (mut contract, bet_id) = oracle_test_setup(1);
+
+        set_predecessor("oracle1.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+        assert_eq!(
+            contract.get_bet(bet_id).unwrap().status,
+            BetStatus::PendingResolution
+        );
+
+        // 3 registered oracles => supermajority is 3 - 3/3 = 2 dissenting votes.
+        set_predecessor("oracle2.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_2".to_string()));
+        assert_eq!(
+            contract.get_bet(bet_id).unwrap().status,
+            BetStatus::PendingResolution,
+            "a single dissenting vote must not yet overturn the pending outcome"
+        );
+
+        set_predecessor("oracle3.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_2".to_string()));
+        assert_eq!(contract.get_bet(bet_id).unwrap().status, BetStatus::Inconclusive);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dispute window has not elapsed")]
+    fn finalize_resolution_rejects_before_dispute_window_elapses() {
+        let (mut contract, bet_id) = oracle_test_setup(1);
+
+        set_predecessor("oracle1.testnet");
+        contract.submit_outcome(bet_id, OracleOutcome::Winner("participant_1".to_string()));
+        assert_eq!(
+            contract.get_bet(bet_id).unwrap().status,
+            BetStatus::PendingResolution
+        );
+
+        // Block timestamp hasn't advanced past dispute_window_ns (1_000_000) since the
+        // threshold was met, so this must still panic.
+        contract.finalize_resolution(bet_id);
     }
 }